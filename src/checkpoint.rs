@@ -0,0 +1,63 @@
+use crate::{Reader, EndOfInput};
+
+/// A previously recorded position in a [`Reader`], captured by
+/// [`Reader::checkpoint`] and restored with [`Reader::restore`].
+///
+/// Following gimli's offset-id concept, this lets a parser record "where it
+/// was" and jump back to it later, which a purely forward-only cursor can't
+/// do on its own — useful for speculative parsing or backpatching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint(usize);
+
+impl Reader {
+    /// Captures the current cursor position, to be restored later with [`restore`](Self::restore).
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.index)
+    }
+
+    /// Rewinds the cursor to a position captured earlier with [`checkpoint`](Self::checkpoint).
+    #[inline]
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.index = checkpoint.0;
+    }
+
+    /// Moves the cursor to an absolute `offset`, without reading anything.
+    pub fn seek_to(&mut self, offset: usize) -> Result<(), EndOfInput> {
+        if offset > self.end() { return Err(EndOfInput); }
+        self.index = offset;
+        Ok(())
+    }
+
+    /// Reads an array of size `N` starting at an absolute `offset`, without
+    /// disturbing the cursor.
+    pub fn read_array_at<const N: usize>(&mut self, offset: usize) -> Result<[u8; N], EndOfInput> {
+        let end = offset.checked_add(N).filter(|&end| end <= self.end()).ok_or(EndOfInput)?;
+        let mut array = [0u8; N];
+        array.copy_from_slice(&self.inner[offset..end]);
+        Ok(array)
+    }
+}
+
+#[test]
+fn checkpoint_test() {
+    use bytes::Bytes;
+
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5]));
+    assert_eq!(reader.read_u8().unwrap(), 1);
+
+    let checkpoint = reader.checkpoint();
+    assert_eq!(reader.read_u8().unwrap(), 2);
+    assert_eq!(reader.read_u8().unwrap(), 3);
+
+    reader.restore(checkpoint);
+    assert_eq!(reader.read_u8().unwrap(), 2);
+
+    reader.seek_to(4).unwrap();
+    assert_eq!(reader.read_u8().unwrap(), 5);
+
+    assert_eq!(reader.read_array_at::<2>(0).unwrap(), [1, 2]);
+    assert_eq!(reader.consumed(), 5);
+
+    assert!(reader.read_array_at::<2>(4).is_err());
+}
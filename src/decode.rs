@@ -35,7 +35,7 @@ pub trait DecodeEndian: Sized {
         return Self::decode_le(reader);
 
         #[cfg(target_endian="big")]
-        return Self::decode_bee(reader);
+        return Self::decode_be(reader);
     }
 }
 
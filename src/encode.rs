@@ -0,0 +1,80 @@
+use crate::Writer;
+
+/// A trait for encoding types. The inverse of [`Decode`](crate::decode::Decode).
+pub trait Encode {
+    /// Encode the type into the writer.
+    fn encode(&self, writer: &mut Writer);
+}
+
+impl Encode for u8 {
+    #[inline]
+    fn encode(&self, writer: &mut Writer) {
+        writer.write_u8(*self);
+    }
+}
+
+impl Encode for i8 {
+    #[inline]
+    fn encode(&self, writer: &mut Writer) {
+        writer.write_i8(*self);
+    }
+}
+
+/// A trait for encoding types that may have different representations in different endians.
+/// The inverse of [`DecodeEndian`](crate::decode::DecodeEndian).
+pub trait EncodeEndian {
+    /// Encode `self` in little-endian byte order.
+    fn encode_le(&self, writer: &mut Writer);
+
+    /// Encode `self` in big-endian byte order.
+    fn encode_be(&self, writer: &mut Writer);
+
+    /// Encode `self` in native-endian byte order.
+    fn encode_ne(&self, writer: &mut Writer) {
+        #[cfg(target_endian="little")]
+        return self.encode_le(writer);
+
+        #[cfg(target_endian="big")]
+        return self.encode_be(writer);
+    }
+}
+
+macro_rules! encode_endian_impl {
+    ($type:ty) => {
+        impl EncodeEndian for $type {
+            #[inline]
+            fn encode_le(&self, writer: &mut Writer) {
+                writer.write_slice(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn encode_be(&self, writer: &mut Writer) {
+                writer.write_slice(&self.to_be_bytes());
+            }
+        }
+    };
+}
+
+encode_endian_impl!(u16);
+encode_endian_impl!(u32);
+encode_endian_impl!(u64);
+encode_endian_impl!(u128);
+
+encode_endian_impl!(i16);
+encode_endian_impl!(i32);
+encode_endian_impl!(i64);
+encode_endian_impl!(i128);
+
+#[test]
+fn encode_roundtrip_test() {
+    use crate::decode::DecodeEndian;
+    use crate::Writer;
+
+    let mut writer = Writer::new();
+    42u8.encode(&mut writer);
+    1_000_000u32.encode_le(&mut writer);
+
+    let mut reader = crate::Reader::new(writer.finish());
+    assert_eq!(reader.read_u8().unwrap(), 42);
+    assert_eq!(u32::decode_le(&mut reader).unwrap(), 1_000_000);
+}
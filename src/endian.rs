@@ -0,0 +1,110 @@
+use crate::Reader;
+
+/// A byte order that a [`Reader`] can be configured to decode multi-byte
+/// integers in. See [`RuntimeEndian`] for the value [`Reader`] stores.
+///
+/// Ported from [gimli](https://github.com/gimli-rs/gimli)'s endianity pattern:
+/// rather than choosing `_le`/`_be` at every call site, a reader is configured
+/// with an endianness once (commonly from a byte in the stream itself, as in
+/// many binary formats) and every subsequent integer read honors it.
+pub trait Endian: Copy + core::fmt::Debug {
+    /// Returns `true` if this endian is big-endian byte order.
+    fn is_big_endian(&self) -> bool;
+
+    /// Returns `true` if this endian is little-endian byte order.
+    #[inline]
+    fn is_little_endian(&self) -> bool {
+        !self.is_big_endian()
+    }
+}
+
+/// Big-endian (most significant byte first) byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BigEndian;
+
+impl Endian for BigEndian {
+    #[inline]
+    fn is_big_endian(&self) -> bool {
+        true
+    }
+}
+
+/// Little-endian (least significant byte first) byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LittleEndian;
+
+impl Endian for LittleEndian {
+    #[inline]
+    fn is_big_endian(&self) -> bool {
+        false
+    }
+}
+
+/// A byte order chosen at runtime, as stored on [`Reader`].
+///
+/// Defaults to [`RuntimeEndian::Big`], matching the byte order `Reader`'s
+/// integer-reading methods have always used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeEndian {
+    /// Big-endian (most significant byte first) byte order.
+    Big,
+    /// Little-endian (least significant byte first) byte order.
+    Little,
+}
+
+impl Default for RuntimeEndian {
+    #[inline]
+    fn default() -> Self {
+        RuntimeEndian::Big
+    }
+}
+
+impl Endian for RuntimeEndian {
+    #[inline]
+    fn is_big_endian(&self) -> bool {
+        matches!(self, RuntimeEndian::Big)
+    }
+}
+
+impl Reader {
+    /// Returns the byte order this reader is currently configured to decode
+    /// multi-byte integers in. Defaults to [`RuntimeEndian::Big`].
+    #[inline]
+    pub fn endian(&self) -> RuntimeEndian {
+        self.endian
+    }
+
+    /// Sets the byte order this reader decodes multi-byte integers in.
+    ///
+    /// Useful when a header byte read earlier in the stream determines the
+    /// order of everything that follows.
+    #[inline]
+    pub fn set_endian(&mut self, endian: RuntimeEndian) {
+        self.endian = endian;
+    }
+
+    /// Builder-style version of [`set_endian`](Self::set_endian).
+    #[inline]
+    pub fn with_endian(mut self, endian: RuntimeEndian) -> Self {
+        self.set_endian(endian);
+        self
+    }
+}
+
+#[test]
+fn configured_endian_test() {
+    use bytes::Bytes;
+
+    // Defaults to big-endian, matching read_u32_be.
+    let mut reader = Reader::new(Bytes::from_static(&[0x00, 0x00, 0x01, 0x00]));
+    assert_eq!(reader.endian(), RuntimeEndian::Big);
+    assert_eq!(reader.read_u32().unwrap(), 256);
+
+    // Switching to little-endian changes the unsuffixed reads...
+    let mut reader = Reader::new(Bytes::from_static(&[0x00, 0x01, 0x00, 0x00])).with_endian(RuntimeEndian::Little);
+    assert_eq!(reader.read_u32().unwrap(), 256);
+
+    // ...but not the explicit _be/_le variants.
+    let mut reader = Reader::new(Bytes::from_static(&[0x00, 0x00, 0x01, 0x00])).with_endian(RuntimeEndian::Little);
+    assert_eq!(reader.read_u32_be().unwrap(), 256);
+}
@@ -1,17 +1,37 @@
-use crate::{Reader, EndOfInput};
+use crate::{Reader, EndOfInput, Endian};
 
 macro_rules! impl_reader_fn {
-    ($type:ident, $size:expr, $func:ident, $docname:expr) => {
+    ($type:ident, $size:expr, $func:ident, $func_be:ident, $func_le:ident, $docname:expr) => {
         #[inline]
-        #[doc="Reads a `"] #[doc=$docname] #[doc="`."]
+        #[doc="Reads a `"] #[doc=$docname] #[doc="` in this reader's configured byte order (see [`Reader::endian`])."]
         pub fn $func(&mut self) -> Result<$type, EndOfInput> {
+            let array = self.read_array::<$size>()?;
+            Ok(match self.endian.is_big_endian() {
+                true => $type::from_be_bytes(array),
+                false => $type::from_le_bytes(array),
+            })
+        }
+
+        #[inline]
+        #[doc="Reads a `"] #[doc=$docname] #[doc="` in big-endian byte order, regardless of this reader's configured byte order."]
+        pub fn $func_be(&mut self) -> Result<$type, EndOfInput> {
             Ok($type::from_be_bytes(self.read_array::<$size>()?))
         }
+
+        #[inline]
+        #[doc="Reads a `"] #[doc=$docname] #[doc="` in little-endian byte order, regardless of this reader's configured byte order."]
+        pub fn $func_le(&mut self) -> Result<$type, EndOfInput> {
+            Ok($type::from_le_bytes(self.read_array::<$size>()?))
+        }
     };
 }
 
 /// Functions that produce integers.
-/// All functions are in big-endian byte order.
+///
+/// The unsuffixed functions (e.g. [`read_u16`](Self::read_u16)) read in this
+/// reader's configured byte order, which defaults to big-endian and can be
+/// changed with [`set_endian`](Self::set_endian). The `_be`/`_le` suffixed
+/// functions always read in that explicit byte order.
 impl Reader {
     /// Reads a `u8`. Identical to [`read_byte`](Self::read_byte).
     #[inline]
@@ -25,12 +45,12 @@ impl Reader {
         unsafe { Ok(core::mem::transmute::<u8, i8>(self.read_u8()?)) }
     }
 
-    impl_reader_fn!(u16, 2, read_u16, "u16");
-    impl_reader_fn!(u32, 4, read_u32, "u32");
-    impl_reader_fn!(u64, 8, read_u64, "u64");
-    impl_reader_fn!(u128, 16, read_u128, "u128");
-    impl_reader_fn!(i16, 2, read_i16, "i16");
-    impl_reader_fn!(i32, 4, read_i32, "i32");
-    impl_reader_fn!(i64, 8, read_i64, "i64");
-    impl_reader_fn!(i128, 16, read_i128, "i128");
-}
\ No newline at end of file
+    impl_reader_fn!(u16, 2, read_u16, read_u16_be, read_u16_le, "u16");
+    impl_reader_fn!(u32, 4, read_u32, read_u32_be, read_u32_le, "u32");
+    impl_reader_fn!(u64, 8, read_u64, read_u64_be, read_u64_le, "u64");
+    impl_reader_fn!(u128, 16, read_u128, read_u128_be, read_u128_le, "u128");
+    impl_reader_fn!(i16, 2, read_i16, read_i16_be, read_i16_le, "i16");
+    impl_reader_fn!(i32, 4, read_i32, read_i32_be, read_i32_le, "i32");
+    impl_reader_fn!(i64, 8, read_i64, read_i64_be, read_i64_le, "i64");
+    impl_reader_fn!(i128, 16, read_i128, read_i128_be, read_i128_le, "i128");
+}
@@ -0,0 +1,104 @@
+//! `std::io::Read`/`BufRead`-compatible surface for [`Reader`](crate::Reader).
+//!
+//! With the `std` feature enabled, [`Reader`](crate::Reader) implements
+//! [`std::io::Read`] and [`std::io::BufRead`] directly. Without it, the
+//! [`Read`] and [`BufRead`] traits defined here (mirroring the `core_io`
+//! approach of providing the `io` traits without `std`) give `no_std`
+//! builds the same shape, so ecosystem code that is generic over `impl
+//! Read`/`impl BufRead` can take a `Reader` in either configuration.
+
+use crate::Reader;
+
+#[cfg(not(feature="std"))]
+use core::convert::Infallible;
+
+/// A `no_std` stand-in for [`std::io::Read`]. Reading from a [`Reader`]
+/// cannot fail, so the error type is [`Infallible`].
+#[cfg(not(feature="std"))]
+pub trait Read {
+    /// Pulls some bytes from this source into `buf`, returning how many bytes were read.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible>;
+}
+
+/// A `no_std` stand-in for [`std::io::BufRead`].
+#[cfg(not(feature="std"))]
+pub trait BufRead: Read {
+    /// Returns the contents of the internal buffer, without advancing the cursor.
+    fn fill_buf(&mut self) -> Result<&[u8], Infallible>;
+
+    /// Marks `amt` bytes of the buffer returned by [`fill_buf`](Self::fill_buf) as read.
+    fn consume(&mut self, amt: usize);
+}
+
+#[cfg(not(feature="std"))]
+impl Read for Reader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+        let amt = self.remaining().min(buf.len());
+        buf[..amt].copy_from_slice(self.read_slice(amt).unwrap());
+        Ok(amt)
+    }
+}
+
+#[cfg(not(feature="std"))]
+impl BufRead for Reader {
+    fn fill_buf(&mut self) -> Result<&[u8], Infallible> {
+        Ok(&self.inner[self.index..self.end()])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.increment(amt);
+    }
+}
+
+#[cfg(feature="std")]
+impl std::io::Read for Reader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let amt = self.remaining().min(buf.len());
+        if amt == 0 { return Ok(0) }
+        buf[..amt].copy_from_slice(self.read_slice(amt).unwrap());
+        Ok(amt)
+    }
+}
+
+/// The underlying [`Bytes`](bytes::Bytes) is always contiguous, so this
+/// never needs to copy or re-fill an intermediate buffer.
+#[cfg(feature="std")]
+impl std::io::BufRead for Reader {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        Ok(&self.inner[self.index..self.end()])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.increment(amt);
+    }
+}
+
+#[cfg(all(test, feature="std"))]
+#[test]
+fn io_read_test() {
+    use std::io::Read as _;
+    use bytes::Bytes;
+
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5]));
+    let mut buf = [0u8; 3];
+    assert_eq!(reader.read(&mut buf).unwrap(), 3);
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], &[4, 5]);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+}
+
+#[cfg(all(test, feature="std"))]
+#[test]
+fn fill_buf_respects_limit_test() {
+    use std::io::BufRead as _;
+    use bytes::Bytes;
+
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5]));
+    let limit = reader.push_limit(2).unwrap();
+    assert_eq!(reader.fill_buf().unwrap(), &[1, 2]);
+
+    reader.consume(2);
+    reader.pop_limit(limit);
+    assert_eq!(reader.fill_buf().unwrap(), &[3, 4, 5]);
+}
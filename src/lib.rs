@@ -3,6 +3,26 @@
 #![warn(missing_docs)]
 
 mod ints;
+mod varint;
+
+/// Traits for decoding types from a [`Reader`].
+pub mod decode;
+mod endian;
+mod writer;
+/// Traits for encoding types into a [`Writer`].
+pub mod encode;
+mod limit;
+mod checkpoint;
+mod maypanic;
+/// `std::io::Read`/`BufRead`-compatible surface, including a `no_std` equivalent.
+pub mod io;
+
+pub use endian::{Endian, BigEndian, LittleEndian, RuntimeEndian};
+pub use writer::Writer;
+pub use limit::Limit;
+pub use checkpoint::Checkpoint;
+pub use maypanic::ReaderMayPanic;
+pub use varint::Varint;
 
 use core::ops::Add;
 #[cfg(feature="std")]
@@ -16,28 +36,42 @@ static EMPTY_SLICE: &[u8] = &[];
 pub struct Reader {
     index: usize,
     inner: Bytes,
+    endian: RuntimeEndian,
+    limit: Option<usize>,
 }
 
 impl Reader {
     /// Creates a new Reader from anything that implements `Into<Bytes>`.
-    /// 
+    ///
     /// This does not allocate by itself, but the `Into<Bytes>` implementation might.
     pub fn new<T: Into<Bytes>>(bytes: T) -> Self {
         Self {
             index: 0,
             inner: bytes.into(),
+            endian: RuntimeEndian::default(),
+            limit: None,
         }
     }
 
     #[inline]
     fn increment(&mut self, amt: usize) {
-        self.index = self.index.add(amt).min(self.inner.len())
+        self.index = self.index.add(amt).min(self.end())
+    }
+
+    /// Returns the logical end of the buffer: the current [limit](Self::push_limit)
+    /// if one is set, otherwise the end of the underlying data.
+    #[inline]
+    fn end(&self) -> usize {
+        match self.limit {
+            Some(limit) => limit.min(self.inner.len()),
+            None => self.inner.len(),
+        }
     }
 
     /// Returns how many bytes have not been read.
     #[inline]
     pub fn remaining(&self) -> usize {
-        self.inner.len().saturating_sub(self.index)
+        self.end().saturating_sub(self.index)
     }
 
     /// Returns `true` if at least `len` many bytes are unread.
@@ -117,13 +151,10 @@ impl Reader {
     }
 }
 
-#[cfg(feature="std")]
-impl std::io::Read for Reader {
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let amt = self.remaining().min(buf.len());
-        if amt == 0 { return Ok(0) }
-        buf[..amt].copy_from_slice(self.read_slice(amt).unwrap());
-        Ok(0)
+impl AsMut<Reader> for Reader {
+    #[inline]
+    fn as_mut(&mut self) -> &mut Reader {
+        self
     }
 }
 
@@ -133,6 +164,8 @@ impl From<Bytes> for Reader {
         Self {
             index: 0,
             inner: value,
+            endian: RuntimeEndian::default(),
+            limit: None,
         }
     }
 }
@@ -151,6 +184,36 @@ impl Display for EndOfInput {
 #[cfg(feature="std")]
 impl Error for EndOfInput {}
 
+/// Error returned when a varint fails to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VarintError {
+    /// The input ended before the varint was complete.
+    EndOfInput,
+    /// The varint was longer than the target type allows, or had trailing
+    /// bits that don't fit in the target type.
+    Malformed,
+}
+
+impl From<EndOfInput> for VarintError {
+    #[inline]
+    fn from(_: EndOfInput) -> Self {
+        VarintError::EndOfInput
+    }
+}
+
+#[cfg(feature="std")]
+impl Display for VarintError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VarintError::EndOfInput => f.write_str("end of input"),
+            VarintError::Malformed => f.write_str("malformed varint"),
+        }
+    }
+}
+
+#[cfg(feature="std")]
+impl Error for VarintError {}
+
 #[test]
 fn static_slice_test() {
     let slice: &'static [u8; 20] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20];
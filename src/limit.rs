@@ -0,0 +1,106 @@
+use crate::{Reader, EndOfInput};
+
+/// A token returned by [`Reader::push_limit`], to be passed back to
+/// [`Reader::pop_limit`] once the length-delimited field has been read.
+///
+/// Dropping a `Limit` instead of passing it to `pop_limit` leaves the
+/// reader bounded by that limit; it does not panic or leak.
+#[derive(Debug)]
+pub struct Limit(Option<usize>);
+
+impl Reader {
+    /// Temporarily bounds the reader to the next `len` bytes, as if the
+    /// buffer ended there. Returns a [`Limit`] capturing the previous bound,
+    /// to be restored with [`pop_limit`](Self::pop_limit).
+    ///
+    /// This is a zero-allocation alternative to [`subreader`](Self::subreader)
+    /// for parsing length-delimited fields (à la protobuf's `CodedInputStream`
+    /// push/pop limits): nested calls to `push_limit`/`pop_limit` form a stack
+    /// via the `Limit` tokens the caller threads through, so recursive parsing
+    /// of framed formats can share one `Reader` and one `Bytes` throughout.
+    pub fn push_limit(&mut self, len: usize) -> Result<Limit, EndOfInput> {
+        let new_end = self.index.checked_add(len).filter(|&e| e <= self.end()).ok_or(EndOfInput)?;
+        let previous = self.limit;
+        self.limit = Some(new_end);
+        Ok(Limit(previous))
+    }
+
+    /// Restores the bound saved in `limit`, advancing the cursor to the end
+    /// of the limit being popped first, so any unread bytes within it are
+    /// skipped.
+    pub fn pop_limit(&mut self, limit: Limit) {
+        self.index = self.end();
+        self.limit = limit.0;
+    }
+}
+
+#[test]
+fn limit_test() {
+    use bytes::Bytes;
+
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5, 6]));
+
+    let limit = reader.push_limit(3).unwrap();
+    assert_eq!(reader.remaining(), 3);
+    assert_eq!(reader.read_u8().unwrap(), 1);
+    assert_eq!(reader.remaining(), 2);
+
+    // Reading past the limit fails even though the underlying buffer has more data.
+    reader.skip(2);
+    assert!(reader.read_u8().is_err());
+
+    reader.pop_limit(limit);
+    assert_eq!(reader.remaining(), 3);
+    assert_eq!(reader.read_u8().unwrap(), 4);
+}
+
+#[test]
+fn nested_limit_test() {
+    use bytes::Bytes;
+
+    // [outer: 6 bytes][2 bytes past the outer limit]
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5, 6, 7, 8]));
+
+    let outer = reader.push_limit(6).unwrap();
+    assert_eq!(reader.read_u8().unwrap(), 1);
+    assert_eq!(reader.remaining(), 5);
+
+    // A nested field declares a shorter length within the outer one.
+    let inner = reader.push_limit(2).unwrap();
+    assert_eq!(reader.remaining(), 2);
+    assert_eq!(reader.read_u8().unwrap(), 2);
+
+    // Reading past the inner limit fails, even though the outer limit allows more.
+    reader.skip(1);
+    assert!(reader.read_u8().is_err());
+
+    // Popping in LIFO order restores the outer bound and skips any unread
+    // bytes within the inner field.
+    reader.pop_limit(inner);
+    assert_eq!(reader.remaining(), 3);
+    assert_eq!(reader.read_u8().unwrap(), 4);
+
+    reader.pop_limit(outer);
+    assert_eq!(reader.remaining(), 2);
+    assert_eq!(reader.read_bytes(2).unwrap()[..], [7, 8]);
+}
+
+#[test]
+fn misused_nesting_order_test() {
+    use bytes::Bytes;
+
+    // Popping an outer limit's token while an inner limit is still active is
+    // a misuse, but it must not panic or corrupt the reader: the outer
+    // token only remembers what bound was active *before* the outer push,
+    // so popping it jumps to the inner limit's end (the bound in effect at
+    // the time of the call) and then discards both limits at once.
+    let mut reader = Reader::new(Bytes::from_static(&[1, 2, 3, 4, 5, 6]));
+
+    let outer = reader.push_limit(4).unwrap();
+    let _inner = reader.push_limit(1).unwrap();
+
+    reader.pop_limit(outer);
+    assert_eq!(reader.consumed(), 1);
+    assert_eq!(reader.remaining(), 5);
+    assert_eq!(reader.read_u8().unwrap(), 2);
+}
@@ -5,7 +5,7 @@ impl Reader {
     /// Returns a [`ReaderMayPanic`].
     /// This crate's no-panic guarantee is forfeited if this function is used.
     #[inline]
-    pub fn may_panic(&mut self) -> ReaderMayPanic {
+    pub fn may_panic(&mut self) -> ReaderMayPanic<'_> {
         ReaderMayPanic(self)
     }
 }
@@ -15,7 +15,7 @@ pub struct ReaderMayPanic<'a>(&'a mut Reader);
 
 impl AsMut<Reader> for ReaderMayPanic<'_> {
     fn as_mut(&mut self) -> &mut Reader {
-        &mut self.0
+        self.0
     }
 }
 
@@ -23,7 +23,7 @@ impl<'a> Deref for ReaderMayPanic<'a> {
     type Target = Reader;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        self.0
     }
 }
 
@@ -33,6 +33,19 @@ impl<'a> DerefMut for ReaderMayPanic<'a> {
     }
 }
 
+impl<'a> ReaderMayPanic<'a> {
+    /// Moves the cursor to an absolute `offset`, panicking if it is out of bounds.
+    pub fn seek_to(&mut self, offset: usize) {
+        self.0.seek_to(offset).unwrap()
+    }
+
+    /// Reads an array of size `N` starting at an absolute `offset`, without
+    /// disturbing the cursor, panicking if it is out of bounds.
+    pub fn read_array_at<const N: usize>(&mut self, offset: usize) -> [u8; N] {
+        self.0.read_array_at(offset).unwrap()
+    }
+}
+
 impl<'a> Buf for ReaderMayPanic<'a> {
     #[inline]
     fn remaining(&self) -> usize {
@@ -40,7 +53,7 @@ impl<'a> Buf for ReaderMayPanic<'a> {
     }
 
     fn chunk(&self) -> &[u8] {
-        &self.inner[self.index..]
+        &self.inner[self.index..self.0.end()]
     }
 
     #[inline]
@@ -52,4 +65,17 @@ impl<'a> Buf for ReaderMayPanic<'a> {
     fn copy_to_bytes(&mut self, len: usize) -> bytes::Bytes {
         self.read_bytes(len).unwrap()
     }
+}
+
+#[test]
+fn chunk_respects_limit_test() {
+    let mut reader = Reader::new(bytes::Bytes::from_static(&[1, 2, 3, 4, 5, 6]));
+    let limit = reader.push_limit(2).unwrap();
+
+    {
+        let panicking = reader.may_panic();
+        assert_eq!(panicking.chunk(), &[1, 2]);
+    }
+
+    reader.pop_limit(limit);
 }
\ No newline at end of file
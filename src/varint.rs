@@ -0,0 +1,149 @@
+use bytes::Bytes;
+use crate::{Reader, Writer, EndOfInput, VarintError};
+use crate::decode::Decode;
+use crate::encode::Encode;
+
+macro_rules! impl_read_varint_unsigned {
+    ($func:ident, $type:ty, $max_bytes:expr, $docname:expr) => {
+        #[doc="Reads a base-128 varint into a `"] #[doc=$docname] #[doc="`, LEB128/protobuf style."]
+        ///
+        /// Reads one byte at a time: the low 7 bits of each byte are shifted into
+        /// the accumulator, and a set high bit (`0x80`) signals that another byte
+        /// follows. Errors if the input ends early, if the continuation bit is
+        /// still set after the maximum number of bytes for this width, or if the
+        /// final byte carries bits that don't fit in the target type.
+        pub fn $func(&mut self) -> Result<$type, VarintError> {
+            let mut result: $type = 0;
+            let mut shift: u32 = 0;
+
+            for i in 0..$max_bytes {
+                let byte = self.read_byte()?;
+                let is_last = i == $max_bytes - 1;
+
+                if is_last && (byte & 0x80) != 0 {
+                    return Err(VarintError::Malformed);
+                }
+
+                let low_bits = (byte & 0x7f) as $type;
+                if is_last {
+                    let used_bits = <$type>::BITS - shift.min(<$type>::BITS);
+                    if used_bits < 7 && (low_bits >> used_bits) != 0 {
+                        return Err(VarintError::Malformed);
+                    }
+                }
+
+                result |= low_bits.checked_shl(shift).unwrap_or(0);
+                shift += 7;
+
+                if byte & 0x80 == 0 {
+                    return Ok(result);
+                }
+            }
+
+            Err(VarintError::Malformed)
+        }
+    };
+}
+
+macro_rules! impl_read_varint_signed {
+    ($func:ident, $unsigned_func:ident, $signed:ty, $docname:expr) => {
+        #[doc="Reads a zigzag-encoded varint into a `"] #[doc=$docname] #[doc="`."]
+        ///
+        /// Decodes the unsigned varint form first, then undoes the zigzag
+        /// mapping (`(n >> 1) ^ -(n & 1)`) to recover the signed value.
+        pub fn $func(&mut self) -> Result<$signed, VarintError> {
+            let n = self.$unsigned_func()?;
+            Ok(((n >> 1) as $signed) ^ -((n & 1) as $signed))
+        }
+    };
+}
+
+/// Variable-length (LEB128/protobuf-style) integer decoding.
+impl Reader {
+    impl_read_varint_unsigned!(read_varint_u32, u32, 5, "u32");
+    impl_read_varint_unsigned!(read_varint_u64, u64, 10, "u64");
+
+    impl_read_varint_signed!(read_varint_i32, read_varint_u32, i32, "i32");
+    impl_read_varint_signed!(read_varint_i64, read_varint_u64, i64, "i64");
+
+    /// Reads a varint-encoded length, then that many bytes.
+    /// See [`Writer::write_length_prefixed`](crate::Writer::write_length_prefixed) for the corresponding write.
+    pub fn read_length_prefixed(&mut self) -> Result<Bytes, VarintError> {
+        let len = self.read_varint_u32()?;
+        Ok(self.read_bytes(len as usize)?)
+    }
+}
+
+/// A newtype that reads and writes as a base-128 varint, for driving
+/// protobuf-wire-format-style fields through the same generic
+/// [`Decode`]/[`Encode`] machinery as fixed-width integers.
+///
+/// [`Decode`]'s error type is [`EndOfInput`], so a malformed varint (as
+/// opposed to one that simply runs out of input) is also reported as
+/// [`EndOfInput`] here; use [`Reader::read_varint_u32`] and friends directly
+/// if [`VarintError::Malformed`] needs to be distinguished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Varint<T>(pub T);
+
+macro_rules! impl_varint_codec {
+    ($type:ty, $read:ident, $write:ident) => {
+        impl Decode for Varint<$type> {
+            #[inline]
+            fn decode(mut reader: impl AsMut<Reader>) -> Result<Self, EndOfInput> {
+                reader.as_mut().$read().map(Varint).map_err(|_| EndOfInput)
+            }
+        }
+
+        impl Encode for Varint<$type> {
+            #[inline]
+            fn encode(&self, writer: &mut Writer) {
+                writer.$write(self.0);
+            }
+        }
+    };
+}
+
+impl_varint_codec!(u32, read_varint_u32, write_varint_u32);
+impl_varint_codec!(u64, read_varint_u64, write_varint_u64);
+impl_varint_codec!(i32, read_varint_i32, write_varint_i32);
+impl_varint_codec!(i64, read_varint_i64, write_varint_i64);
+
+#[test]
+fn varint_test() {
+    use bytes::Bytes;
+
+    // 300 encodes to [0xAC, 0x02] in base-128 varint form.
+    let mut reader = Reader::new(Bytes::from_static(&[0xAC, 0x02]));
+    assert_eq!(reader.read_varint_u32().unwrap(), 300);
+
+    // Single-byte values round-trip directly.
+    let mut reader = Reader::new(Bytes::from_static(&[0x01]));
+    assert_eq!(reader.read_varint_u64().unwrap(), 1);
+
+    // Zigzag: -1 encodes to 1, which is a single byte.
+    let mut reader = Reader::new(Bytes::from_static(&[0x01]));
+    assert_eq!(reader.read_varint_i32().unwrap(), -1);
+
+    // A continuation bit that never terminates within the byte budget is malformed.
+    let mut reader = Reader::new(Bytes::from_static(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80]));
+    assert_eq!(reader.read_varint_u32().unwrap_err(), VarintError::Malformed);
+
+    // Running out of input partway through is EndOfInput.
+    let mut reader = Reader::new(Bytes::from_static(&[0x80]));
+    assert_eq!(reader.read_varint_u32().unwrap_err(), VarintError::EndOfInput);
+}
+
+#[test]
+fn varint_decode_encode_test() {
+    let mut writer = Writer::new();
+    Varint(300u32).encode(&mut writer);
+    Varint(-1i64).encode(&mut writer);
+
+    let mut reader = Reader::new(writer.finish());
+    assert_eq!(Varint::<u32>::decode(&mut reader).unwrap(), Varint(300));
+    assert_eq!(Varint::<i64>::decode(&mut reader).unwrap(), Varint(-1));
+
+    // A malformed varint is reported as EndOfInput through the Decode trait.
+    let mut reader = Reader::new(Bytes::from_static(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80]));
+    assert_eq!(Varint::<u32>::decode(&mut reader).unwrap_err(), EndOfInput);
+}
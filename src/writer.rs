@@ -0,0 +1,197 @@
+use bytes::{Bytes, BytesMut};
+use crate::{Endian, RuntimeEndian};
+
+macro_rules! impl_writer_fn {
+    ($type:ident, $func:ident, $func_be:ident, $func_le:ident, $docname:expr) => {
+        #[inline]
+        #[doc="Writes a `"] #[doc=$docname] #[doc="` in this writer's configured byte order (see [`Writer::endian`])."]
+        pub fn $func(&mut self, val: $type) {
+            match self.endian.is_big_endian() {
+                true => self.write_slice(&val.to_be_bytes()),
+                false => self.write_slice(&val.to_le_bytes()),
+            }
+        }
+
+        #[inline]
+        #[doc="Writes a `"] #[doc=$docname] #[doc="` in big-endian byte order, regardless of this writer's configured byte order."]
+        pub fn $func_be(&mut self, val: $type) {
+            self.write_slice(&val.to_be_bytes());
+        }
+
+        #[inline]
+        #[doc="Writes a `"] #[doc=$docname] #[doc="` in little-endian byte order, regardless of this writer's configured byte order."]
+        pub fn $func_le(&mut self, val: $type) {
+            self.write_slice(&val.to_le_bytes());
+        }
+    };
+}
+
+/// Forward-only cursor for writing bytes, mirroring [`Reader`](crate::Reader).
+///
+/// Backed by a [`BytesMut`] that grows as needed, so writes never fail.
+pub struct Writer {
+    inner: BytesMut,
+    endian: RuntimeEndian,
+}
+
+impl Writer {
+    /// Creates a new, empty `Writer`.
+    pub fn new() -> Self {
+        Self { inner: BytesMut::new(), endian: RuntimeEndian::default() }
+    }
+
+    /// Creates a new, empty `Writer` with at least `capacity` bytes of
+    /// pre-allocated space.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { inner: BytesMut::with_capacity(capacity), endian: RuntimeEndian::default() }
+    }
+
+    /// Returns the byte order this writer is currently configured to write
+    /// multi-byte integers in. Defaults to [`RuntimeEndian::Big`].
+    #[inline]
+    pub fn endian(&self) -> RuntimeEndian {
+        self.endian
+    }
+
+    /// Sets the byte order this writer writes multi-byte integers in.
+    #[inline]
+    pub fn set_endian(&mut self, endian: RuntimeEndian) {
+        self.endian = endian;
+    }
+
+    /// Builder-style version of [`set_endian`](Self::set_endian).
+    #[inline]
+    pub fn with_endian(mut self, endian: RuntimeEndian) -> Self {
+        self.set_endian(endian);
+        self
+    }
+
+    /// Returns how many bytes have been written so far.
+    #[inline]
+    pub fn written(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Writes a single byte. Identical to [`write_u8`](Self::write_u8).
+    #[inline]
+    pub fn write_byte(&mut self, val: u8) {
+        self.inner.extend_from_slice(&[val]);
+    }
+
+    /// Writes the given slice, without a length prefix.
+    #[inline]
+    pub fn write_slice(&mut self, slice: &[u8]) {
+        self.inner.extend_from_slice(slice);
+    }
+
+    /// Writes the given [`Bytes`], without a length prefix.
+    #[inline]
+    pub fn write_bytes(&mut self, bytes: impl Into<Bytes>) {
+        self.write_slice(&bytes.into());
+    }
+
+    /// Writes a `u8`. Identical to [`write_byte`](Self::write_byte).
+    #[inline]
+    pub fn write_u8(&mut self, val: u8) {
+        self.write_byte(val);
+    }
+
+    /// Writes an `i8`.
+    #[inline]
+    pub fn write_i8(&mut self, val: i8) {
+        self.write_byte(val as u8);
+    }
+
+    impl_writer_fn!(u16, write_u16, write_u16_be, write_u16_le, "u16");
+    impl_writer_fn!(u32, write_u32, write_u32_be, write_u32_le, "u32");
+    impl_writer_fn!(u64, write_u64, write_u64_be, write_u64_le, "u64");
+    impl_writer_fn!(u128, write_u128, write_u128_be, write_u128_le, "u128");
+    impl_writer_fn!(i16, write_i16, write_i16_be, write_i16_le, "i16");
+    impl_writer_fn!(i32, write_i32, write_i32_be, write_i32_le, "i32");
+    impl_writer_fn!(i64, write_i64, write_i64_be, write_i64_le, "i64");
+    impl_writer_fn!(i128, write_i128, write_i128_be, write_i128_le, "i128");
+
+    /// Writes `val` as a base-128 varint, LEB128/protobuf style.
+    /// See [`Reader::read_varint_u32`](crate::Reader::read_varint_u32) for the corresponding read.
+    pub fn write_varint_u32(&mut self, val: u32) {
+        self.write_varint_u64(val as u64);
+    }
+
+    /// Writes `val` as a base-128 varint, LEB128/protobuf style.
+    /// See [`Reader::read_varint_u64`](crate::Reader::read_varint_u64) for the corresponding read.
+    pub fn write_varint_u64(&mut self, mut val: u64) {
+        loop {
+            let byte = (val & 0x7f) as u8;
+            val >>= 7;
+
+            if val == 0 {
+                self.write_byte(byte);
+                break;
+            }
+
+            self.write_byte(byte | 0x80);
+        }
+    }
+
+    /// Writes `val` as a zigzag-encoded varint.
+    /// See [`Reader::read_varint_i32`](crate::Reader::read_varint_i32) for the corresponding read.
+    pub fn write_varint_i32(&mut self, val: i32) {
+        self.write_varint_u32(((val << 1) ^ (val >> 31)) as u32);
+    }
+
+    /// Writes `val` as a zigzag-encoded varint.
+    /// See [`Reader::read_varint_i64`](crate::Reader::read_varint_i64) for the corresponding read.
+    pub fn write_varint_i64(&mut self, val: i64) {
+        self.write_varint_u64(((val << 1) ^ (val >> 63)) as u64);
+    }
+
+    /// Writes `bytes.len()` as a varint, followed by `bytes` itself.
+    /// See [`Reader::read_length_prefixed`](crate::Reader::read_length_prefixed) for the corresponding read.
+    pub fn write_length_prefixed(&mut self, bytes: &[u8]) {
+        self.write_varint_u32(bytes.len() as u32);
+        self.write_slice(bytes);
+    }
+
+    /// Consumes the `Writer`, returning the written bytes.
+    pub fn finish(self) -> Bytes {
+        self.inner.freeze()
+    }
+}
+
+impl Default for Writer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn writer_roundtrip_test() {
+    use crate::Reader;
+
+    let mut writer = Writer::new();
+    writer.write_u8(1);
+    writer.write_u32(300);
+    writer.write_varint_u32(300);
+    writer.write_varint_i32(-1);
+    writer.write_length_prefixed(&[1, 2, 3]);
+
+    let mut reader = Reader::new(writer.finish());
+    assert_eq!(reader.read_u8().unwrap(), 1);
+    assert_eq!(reader.read_u32().unwrap(), 300);
+    assert_eq!(reader.read_varint_u32().unwrap(), 300);
+    assert_eq!(reader.read_varint_i32().unwrap(), -1);
+    assert_eq!(&*reader.read_length_prefixed().unwrap(), &[1, 2, 3]);
+    assert_eq!(reader.remaining(), 0);
+}
+
+#[test]
+fn writer_configured_endian_test() {
+    use crate::{Reader, RuntimeEndian};
+
+    let mut writer = Writer::new().with_endian(RuntimeEndian::Little);
+    writer.write_u32(300);
+
+    let mut reader = Reader::new(writer.finish()).with_endian(RuntimeEndian::Little);
+    assert_eq!(reader.read_u32().unwrap(), 300);
+}